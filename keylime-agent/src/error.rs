@@ -60,6 +60,8 @@ pub(crate) enum Error {
     NumParse(#[from] std::num::ParseIntError),
     #[error("Crypto error: {0}")]
     Crypto(#[from] openssl::error::ErrorStack),
+    #[error("Unsupported key type: {0:?}")]
+    UnsupportedKeyType(openssl::pkey::Id),
     #[cfg(feature = "with-zmq")]
     #[error("ZMQ error: {0}")]
     Zmq(#[from] zmq::Error),
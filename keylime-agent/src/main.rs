@@ -260,6 +260,14 @@ async fn main() -> Result<()> {
 
     info!("Starting server with API version {}...", API_VERSION);
 
+    // Check that the crypto backend supports the operations the agent needs.
+    // Failures are only reported, as they may not affect every deployment
+    for (capability, result) in crypto::crypto_self_test() {
+        if let Err(e) = result {
+            warn!("Crypto self-test failed for {capability}, the operation may not be supported by the current OpenSSL configuration: {e}");
+        }
+    }
+
     let mut ctx = tpm::Context::new()?;
 
     //  Retrieve the TPM Vendor, this allows us to warn if someone is using a
@@ -645,6 +653,7 @@ async fn main() -> Result<()> {
             &cert,
             &nk_priv,
             keylime_ca_certs,
+            Vec::new(),
         )?);
     } else {
         mtls_cert = None;